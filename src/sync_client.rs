@@ -62,15 +62,39 @@ impl SyncHdfsClient {
 }
 
 
+/// Default size of the read-ahead buffer used by `Read for ReadHdfsFile`.
+const DEFAULT_BUFFER_SIZE: i64 = 1024 * 1024;
+
+/// Default segment size used by `ReadHdfsFile::read_parallel`.
+const DEFAULT_PARALLEL_SEGMENT_SIZE: i64 = 8 * 1024 * 1024;
+/// Default number of concurrent WebHDFS `OPEN`s used by `ReadHdfsFile::read_parallel`.
+const DEFAULT_PARALLEL_CONCURRENCY: usize = 4;
+
+/// Splits `[start, end)` into consecutive `(offset, length)` segments of at most
+/// `segment_size` bytes each, used to fan a range read out into concurrent requests.
+fn plan_segments(start: i64, end: i64, segment_size: i64) -> Vec<(i64, i64)> {
+    let mut segments = Vec::new();
+    let mut pos = start;
+    while pos < end {
+        let len = segment_size.min(end - pos);
+        segments.push((pos, len));
+        pos += len;
+    }
+    segments
+}
+
 /// HDFS file read object.
-/// 
-/// Note about position and offset types: we assume that all hdfs/webhdfs lengths and offsets are actually signed 64-bit integers, 
+///
+/// Note about position and offset types: we assume that all hdfs/webhdfs lengths and offsets are actually signed 64-bit integers,
 /// according to protocol specifications and JVM specifics (no unsigned).
 pub struct ReadHdfsFile {
     cx: SyncHdfsClient,
     path: String,
     len: i64,
-    pos: i64
+    pos: i64,
+    buffer_size: i64,
+    buffer: Vec<u8>,
+    buffer_start: i64,
 }
 
 impl ReadHdfsFile {
@@ -80,27 +104,117 @@ impl ReadHdfsFile {
         Ok(Self::new(cx, path, stat.file_status.length, 0))
     }
     fn new(cx: SyncHdfsClient, path: String, len: i64, pos: i64) -> Self {
-        Self { cx, path, len, pos }
+        Self { cx, path, len, pos, buffer_size: DEFAULT_BUFFER_SIZE, buffer: Vec::new(), buffer_start: 0 }
     }
     /// File length in bytes
     pub fn len(&self) -> u64 { self.len as u64 }
 
+    /// Sets the size of the read-ahead block fetched by `Read::read` on a cache miss.
+    ///
+    /// Defaults to `DEFAULT_BUFFER_SIZE` (1 MiB). Larger values collapse more small reads
+    /// into a single WebHDFS `OPEN`, at the cost of over-fetching on short, scattered reads.
+    pub fn with_buffer_size(mut self, buffer_size: i64) -> Self {
+        self.buffer_size = buffer_size;
+        self
+    }
+
+    /// True if the read-ahead buffer currently holds data covering `pos`.
+    fn buffer_contains(&self, pos: i64) -> bool {
+        !self.buffer.is_empty() && pos >= self.buffer_start && pos < self.buffer_start + self.buffer.len() as i64
+    }
+
+    /// Fetches a fresh read-ahead block starting at `self.pos`, replacing the current buffer.
+    fn fill_buffer(&mut self) -> IoResult<()> {
+        let remaining = self.len - self.pos;
+        if remaining <= 0 {
+            self.buffer.clear();
+            return Ok(());
+        }
+        let want = self.buffer_size.min(remaining);
+        let mut block = vec![0u8; want as usize];
+        let n = self.read_at(&mut block, self.pos)?;
+        block.truncate(n);
+        self.buffer_start = self.pos;
+        self.buffer = block;
+        Ok(())
+    }
+
     /// Splits self into `(sync_client, path, (pos, len))`
     pub fn into_parts(self) -> (SyncHdfsClient, String, (i64, i64)) { (self.cx, self.path, (self.pos, self.len)) }
-}
 
-impl Read for ReadHdfsFile {
-    fn read(&mut self, buf: &mut [u8]) -> IoResult<usize> {
+    /// Reads `length` bytes starting at `offset` into `buf`, using `DEFAULT_PARALLEL_SEGMENT_SIZE`
+    /// segments fetched with up to `DEFAULT_PARALLEL_CONCURRENCY` concurrent WebHDFS `OPEN`s.
+    ///
+    /// See `read_parallel_with` to override the segment size and concurrency.
+    pub fn read_parallel(&self, buf: &mut [u8], offset: i64, length: i64) -> IoResult<usize> {
+        self.read_parallel_with(buf, offset, length, DEFAULT_PARALLEL_SEGMENT_SIZE, DEFAULT_PARALLEL_CONCURRENCY)
+    }
+
+    /// Reads `length` bytes starting at `offset` into `buf`, split into `segment_size`-sized
+    /// segments fetched by up to `concurrency` concurrent WebHDFS `OPEN`s.
+    ///
+    /// `offset`/`length` are clamped to the file's length. Segments are reassembled into `buf`
+    /// in their original order regardless of completion order, so the result is identical to a
+    /// single sequential read -- only the wall-clock time differs. Because `SyncHdfsClient`
+    /// drives everything on a single `current_thread::Runtime`, "concurrency" here means
+    /// concurrent futures polled on that one runtime rather than OS threads: this must not be
+    /// called from more than one thread against the same underlying client.
+    pub fn read_parallel_with(&self, buf: &mut [u8], offset: i64, length: i64, segment_size: i64, concurrency: usize) -> IoResult<usize> {
+        let end = (offset + length).min(self.len);
+        if end <= offset {
+            return Ok(0);
+        }
+        let total = (end - offset) as usize;
+        if buf.len() < total {
+            return Err(IoError::new(IoErrorKind::InvalidInput, "buffer too small for requested range"));
+        }
+
+        let segments = plan_segments(offset, end, segment_size);
+
+        let cx = &self.cx;
+        let path = &self.path;
+
+        let fetches = futures::stream::iter_ok::<_, Error>(segments)
+            .map(|(seg_offset, seg_len)| {
+                cx.acx.open(path, OpenOptions::new().offset(seg_offset).length(seg_len))
+                    .fold(Vec::new(), |mut acc, chunk| {
+                        acc.extend_from_slice(&chunk);
+                        Ok::<_, Error>(acc)
+                    })
+                    .map(move |data| (seg_offset, data))
+            })
+            .buffer_unordered(concurrency.max(1));
+
+        let assemble = fetches.for_each(|(seg_offset, data)| {
+            let start = (seg_offset - offset) as usize;
+            if start < buf.len() {
+                // Bounded/truncating write, not `copy_from_slice`: a server that doesn't honor
+                // `length` precisely and returns more bytes than requested must not panic here.
+                // `Write for &mut [u8]` never actually errs, so there's nothing to propagate.
+                let _ = (&mut buf[start..]).write(&data);
+            }
+            Ok(())
+        });
+        cx.exec(assemble)?;
+
+        Ok(total)
+    }
 
+    /// Reads into `buf` starting at the explicit `offset`, without touching this file's cursor.
+    ///
+    /// Unlike `Read::read`, this takes `&self`, so several logical readers can share one
+    /// `ReadHdfsFile` and issue independent positional reads instead of interleaving
+    /// `seek` + `read` against a shared cursor. Each call blocks on the client's runtime
+    /// until its `OPEN` stream is drained, so calls do not overlap.
+    pub fn read_at(&self, buf: &mut [u8], offset: i64) -> IoResult<usize> {
         let buf_len: i64 = buf.len().try_into().map_err(|_| IoError::new(IoErrorKind::InvalidInput, "buffer too big"))?;
-        let mut s = self.cx.acx.open(&self.path, OpenOptions::new().offset(self.pos).length(buf_len));
+        let mut s = self.cx.acx.open(&self.path, OpenOptions::new().offset(offset).length(buf_len));
         let mut pos: usize = 0;
-        
+
         loop {
             match self.cx.exec(s.into_future().map_err(|(e, _s)| e)) {
                 Ok((Some(chunk), s1)) => {
                     s = s1;
-                    self.pos += chunk.len() as i64;
                     let bcount = (&mut buf[pos..]).write(&chunk)?;
                     pos += bcount;
                 }
@@ -115,6 +229,25 @@ impl Read for ReadHdfsFile {
     }
 }
 
+impl Read for ReadHdfsFile {
+    fn read(&mut self, buf: &mut [u8]) -> IoResult<usize> {
+        if self.pos >= self.len {
+            return Ok(0);
+        }
+        if !self.buffer_contains(self.pos) {
+            self.fill_buffer()?;
+        }
+        if self.buffer.is_empty() {
+            return Ok(0);
+        }
+
+        let start = (self.pos - self.buffer_start) as usize;
+        let n = (&mut buf[..]).write(&self.buffer[start..])?;
+        self.pos += n as i64;
+        Ok(n)
+    }
+}
+
 impl Seek for ReadHdfsFile {
     fn seek(&mut self, pos: SeekFrom) -> IoResult<u64> {
         //1. A seek beyond the end of a stream is allowed, but behavior is defined by the implementation --
@@ -141,32 +274,244 @@ impl Seek for ReadHdfsFile {
 }
 
 
+/// Default capacity of the write buffer accumulated by `Write for WriteHdfsFile` before
+/// it is flushed as a single WebHDFS `APPEND`.
+const DEFAULT_WRITE_BUFFER_SIZE: usize = 4 * 1024 * 1024;
+
 /// HDFS file write object
 pub struct WriteHdfsFile {
     cx: SyncHdfsClient,
     path: String,
+    buffer_size: usize,
+    buffer: Vec<u8>,
 }
 
 impl WriteHdfsFile {
     pub fn create(cx: SyncHdfsClient, path: String, opts: CreateOptions) -> Result<WriteHdfsFile> {
         cx.exec(cx.acx.create(&path, vec![], opts))?;
-        Ok(Self { cx, path })
+        Ok(Self { cx, path, buffer_size: DEFAULT_WRITE_BUFFER_SIZE, buffer: Vec::new() })
     }
     pub fn append(cx: SyncHdfsClient, path: String) -> Result<WriteHdfsFile> {
-        Ok(Self { cx, path })
+        Ok(Self { cx, path, buffer_size: DEFAULT_WRITE_BUFFER_SIZE, buffer: Vec::new() })
     }
-}
 
-impl Write for WriteHdfsFile {
-    fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
-        //TODO this is apparently a performance killer. We need at least faster buffer copy, and ideally zero-copy
+    /// Sets the capacity of the write buffer accumulated before an `APPEND` is issued.
+    ///
+    /// Defaults to `DEFAULT_WRITE_BUFFER_SIZE` (4 MiB). Larger values turn more small
+    /// `write()` calls into a single request, at the cost of holding more unflushed data
+    /// in memory between appends.
+    pub fn with_buffer_size(mut self, buffer_size: usize) -> Self {
+        self.buffer_size = buffer_size;
+        self
+    }
+
+    /// The `(client, path)` that writes through this handle actually land on.
+    fn append_destination(&self) -> (SyncHdfsClient, String) {
+        (self.cx.clone(), self.path.clone())
+    }
+
+    /// Sends the currently buffered bytes as a single `APPEND`, if any are buffered.
+    fn flush_chunk(&mut self) -> IoResult<()> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+        let chunk = std::mem::replace(&mut self.buffer, Vec::new());
+        let f = self.cx.acx.append(&self.path, chunk.clone(), AppendOptions::new());
+        match self.cx.exec(f) {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                // The APPEND didn't land -- put the bytes back so a retried write()/flush()
+                // (or Drop) doesn't silently lose them.
+                self.buffer = chunk;
+                Err(err.into())
+            }
+        }
+    }
+
+    /// Appends `buf` at `offset`, without going through the buffered `Write` impl.
+    ///
+    /// WebHDFS only ever appends at the current end of file, so `offset` must match the
+    /// file's current length -- this lets a caller state the position it expects to write at
+    /// and get an error on mismatch, instead of silently appending out of order.
+    pub fn write_at(&mut self, buf: &[u8], offset: i64) -> IoResult<usize> {
+        self.flush_chunk()?;
+        let stat = self.cx.stat(&self.path)?;
+        if stat.file_status.length != offset {
+            return Err(IoError::new(IoErrorKind::InvalidInput,
+                format!("write_at offset {} does not match current file length {}", offset, stat.file_status.length)));
+        }
         let mut b: Vec<u8> = Vec::with_capacity(buf.len());
         b.extend(buf.iter());
         let f = self.cx.acx.append(&self.path, b, AppendOptions::new());
         let _ = self.cx.exec(f)?;
         Ok(buf.len())
     }
+}
+
+impl Write for WriteHdfsFile {
+    fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+        // Split the incoming slice into buffer_size-sized pieces so a single large write()
+        // still respects the configured cap instead of growing self.buffer past it.
+        let cap = self.buffer_size.max(1);
+        let mut remaining = buf;
+        while !remaining.is_empty() {
+            let take = (cap - self.buffer.len()).min(remaining.len());
+            self.buffer.extend_from_slice(&remaining[..take]);
+            remaining = &remaining[take..];
+            if self.buffer.len() >= cap {
+                self.flush_chunk()?;
+            }
+        }
+        Ok(buf.len())
+    }
     fn flush(&mut self) -> IoResult<()> {
-        Ok(())
+        self.flush_chunk()
+    }
+}
+
+impl Drop for WriteHdfsFile {
+    fn drop(&mut self) {
+        // Best-effort: Drop can't propagate I/O errors, but we still want to flush
+        // whatever is left in the buffer rather than silently lose unflushed data.
+        let _ = self.flush_chunk();
+    }
+}
+
+/// Default chunk size read ahead while the previous chunk's `APPEND` is still in flight,
+/// used by `copy`.
+const DEFAULT_COPY_BUFFER_SIZE: i64 = 1024 * 1024;
+
+/// Copies the remainder of `reader` (from its current cursor) into `writer`.
+///
+/// Uses `DEFAULT_COPY_BUFFER_SIZE`-sized chunks; see `copy_with_buffer_size` to override it.
+pub fn copy(reader: &mut ReadHdfsFile, writer: &mut WriteHdfsFile) -> IoResult<u64> {
+    copy_with_buffer_size(reader, writer, DEFAULT_COPY_BUFFER_SIZE)
+}
+
+/// Resolves the read/write endpoints `copy` targets: `(read_cx, read_path, write_cx, write_path)`.
+///
+/// Pulled out of `copy_with_buffer_size` so the destination it appends to -- `writer`'s, never
+/// `reader`'s -- can be asserted on without driving any real I/O.
+fn copy_endpoints(reader: &ReadHdfsFile, writer: &WriteHdfsFile) -> (SyncHdfsClient, String, SyncHdfsClient, String) {
+    let (write_cx, write_path) = writer.append_destination();
+    (reader.cx.clone(), reader.path.clone(), write_cx, write_path)
+}
+
+/// Wraps `cause` with how many bytes had already been durably appended to `writer` when it
+/// occurred, so a caller can resume instead of re-copying (and re-appending) those bytes.
+fn copy_error(bytes_copied: u64, cause: IoError) -> IoError {
+    IoError::new(cause.kind(), format!("copy stopped after {} bytes: {}", bytes_copied, cause))
+}
+
+/// Copies the remainder of `reader` into `writer`, reading `buffer_size`-sized chunks.
+///
+/// Unlike wiring `Read`/`Write` through `std::io::copy`, the next chunk's WebHDFS `OPEN` is
+/// driven on the shared runtime concurrently with the previous chunk's `APPEND`, so a stall on
+/// the append side does not block the next read from starting. Any data already buffered in
+/// `writer` is flushed first so appends stay in order. `reader`'s cursor and the returned byte
+/// count only advance chunk-by-chunk as each chunk's `APPEND` is confirmed, so on error both
+/// reflect exactly what's durable on `writer` -- a retried `copy` picks up from there instead of
+/// re-appending already-written bytes. The returned `io::Error` also names that byte count.
+///
+/// `reader` and `writer` must share the same underlying `SyncHdfsClient` runtime (the normal
+/// case: one client, two paths on the same cluster) -- the joined OPEN/APPEND futures are
+/// driven together on `reader`'s runtime.
+pub fn copy_with_buffer_size(reader: &mut ReadHdfsFile, writer: &mut WriteHdfsFile, buffer_size: i64) -> IoResult<u64> {
+    writer.flush()?;
+    let (cx, read_path, write_cx, write_path) = copy_endpoints(reader, writer);
+    let mut total: u64 = 0;
+
+    fn read_segment(cx: &SyncHdfsClient, path: &str, pos: i64, len: i64) -> impl Future<Item=Vec<u8>, Error=Error> {
+        cx.acx.open(path, OpenOptions::new().offset(pos).length(len))
+            .fold(Vec::new(), |mut acc, chunk| {
+                acc.extend_from_slice(&chunk);
+                Ok::<_, Error>(acc)
+            })
+    }
+
+    let next_len = |pos: i64| (reader.len - pos).max(0).min(buffer_size);
+
+    let mut pos = reader.pos;
+    let mut len = next_len(pos);
+    if len <= 0 {
+        return Ok(0);
     }
-}
\ No newline at end of file
+    let mut current: Vec<u8> = match cx.exec(read_segment(&cx, &read_path, pos, len)) {
+        Ok(data) => data,
+        Err(err) => return Err(copy_error(total, err.into())),
+    };
+
+    while !current.is_empty() {
+        let seg_len = current.len() as u64;
+        let next_pos = pos + seg_len as i64;
+        let append_fut = write_cx.acx.append(&write_path, current, AppendOptions::new());
+
+        len = next_len(next_pos);
+        let step = if len > 0 {
+            let read_fut = read_segment(&cx, &read_path, next_pos, len);
+            cx.exec(append_fut.join(read_fut)).map(|(_, next)| next)
+        } else {
+            cx.exec(append_fut).map(|_| Vec::new())
+        };
+
+        current = match step {
+            // Only now is this chunk's APPEND confirmed durable -- commit its progress before
+            // touching anything from the next chunk, so a failure further on can't roll it back.
+            Ok(next) => {
+                pos = next_pos;
+                total += seg_len;
+                reader.pos = pos;
+                next
+            }
+            Err(err) => return Err(copy_error(total, err.into())),
+        };
+    }
+
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plan_segments_covers_the_range_without_gaps_or_overlap() {
+        assert_eq!(plan_segments(10, 25, 6), vec![(10, 6), (16, 6), (22, 3)]);
+    }
+
+    #[test]
+    fn plan_segments_of_an_empty_range_is_empty() {
+        assert_eq!(plan_segments(5, 5, 6), Vec::<(i64, i64)>::new());
+        assert_eq!(plan_segments(5, 3, 6), Vec::<(i64, i64)>::new());
+    }
+
+    #[test]
+    fn plan_segments_never_exceeds_segment_size() {
+        let segments = plan_segments(0, 100, 7);
+        assert_eq!(segments.iter().map(|(_, len)| *len).sum::<i64>(), 100);
+        assert!(segments.iter().all(|(_, len)| *len <= 7 && *len > 0));
+    }
+
+    fn test_client() -> SyncHdfsClient {
+        SyncHdfsClient::from_entrypoint("http://localhost:50070".parse().unwrap())
+            .expect("constructing a client must not require a live NameNode")
+    }
+
+    // Regression test for the chunk0-4 review bug: `copy()` appended the data it read back
+    // onto `reader`'s path/client instead of `writer`'s, silently duplicating data onto the
+    // source file of a cross-path copy instead of writing the destination. `copy_with_buffer_size`
+    // resolves its endpoints through `copy_endpoints` before driving any real I/O, so calling it
+    // directly exercises the exact logic the bug was in, without needing a live NameNode.
+    #[test]
+    fn copy_appends_to_writers_path_not_readers() {
+        let cx = test_client();
+        let reader = ReadHdfsFile::new(cx.clone(), "/source".to_string(), 0, 0);
+        let writer = WriteHdfsFile { cx: cx.clone(), path: "/dest".to_string(), buffer_size: DEFAULT_WRITE_BUFFER_SIZE, buffer: Vec::new() };
+
+        let (_, read_path, _, write_path) = copy_endpoints(&reader, &writer);
+
+        assert_eq!(read_path, "/source");
+        assert_eq!(write_path, "/dest");
+        assert_ne!(write_path, read_path);
+    }
+}